@@ -1,19 +1,88 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{anyhow, bail, Result};
 use once_cell::sync::OnceCell;
-use tokenizers::Tokenizer;
+use tokenizers::models::bpe::BPE;
+use tokenizers::pre_tokenizers::byte_level::ByteLevel;
+use tokenizers::{AddedToken, PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
 use wasm_bindgen::prelude::*;
 
-/// Global tokenizer instance loaded once at startup.
-static TOKENIZER: OnceCell<Tokenizer> = OnceCell::new();
+/// Name of the tokenizer slot used by the legacy single-tokenizer API
+/// (`init`, `count`, `encode`).
+const DEFAULT_TOKENIZER_NAME: &str = "default";
 
-/// Try to resolve a usable `tokenizer.json` path from the given input path.
-/// - If `path` is a directory, returns `path/tokenizer.json` if it exists.
-/// - If `path` is a file ending with `.json`, returns it as-is.
+/// Per-slot encoding configuration: whether special tokens are added during
+/// encoding, and the truncation/padding lengths (if any) applied so counts
+/// reflect what the model will actually see.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenizerSettings {
+    pub add_special_tokens: bool,
+    pub truncation: Option<usize>,
+    pub padding: Option<usize>,
+}
+
+/// A loaded tokenizer together with the settings it was configured with.
+struct TokenizerEntry {
+    tokenizer: Tokenizer,
+    settings: TokenizerSettings,
+}
+
+/// Registry of named tokenizer instances, loaded on demand and kept for the
+/// lifetime of the process. Multiple tokenizers (e.g. a GPT-2 BPE tokenizer
+/// alongside a LLaMA SentencePiece one) can be loaded side by side under
+/// distinct names.
+static TOKENIZERS: OnceCell<Mutex<HashMap<String, Arc<TokenizerEntry>>>> = OnceCell::new();
+
+fn registry() -> &'static Mutex<HashMap<String, Arc<TokenizerEntry>>> {
+    TOKENIZERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Apply truncation/padding settings to a tokenizer in place.
+fn apply_settings(tokenizer: &mut Tokenizer, settings: &TokenizerSettings) -> Result<()> {
+    match settings.truncation {
+        Some(max_length) => {
+            tokenizer
+                .with_truncation(Some(TruncationParams { max_length, ..Default::default() }))
+                .map_err(|e| anyhow!("Failed to configure truncation: {}", e))?;
+        }
+        None => {
+            tokenizer
+                .with_truncation(None)
+                .map_err(|e| anyhow!("Failed to clear truncation: {}", e))?;
+        }
+    }
+
+    match settings.padding {
+        Some(length) => {
+            tokenizer.with_padding(Some(PaddingParams {
+                strategy: PaddingStrategy::Fixed(length),
+                ..Default::default()
+            }));
+        }
+        None => {
+            tokenizer.with_padding(None);
+        }
+    }
+
+    Ok(())
+}
+
+/// Try to resolve a usable tokenizer definition from the given input path.
+/// - If `path` is a directory, prefers `tokenizer.json`, then a GPT-2 style
+///   `vocab.json` + `merges.txt` pair.
+/// - If `path` is a file, accepts `.json` directly.
+///
+/// A raw SentencePiece `.model` file (the binary protobuf format LLaMA-style
+/// tokenizers ship) is deliberately not accepted here: the `tokenizers` crate
+/// has no parser for that format, only for the JSON Unigram dump embedded in
+/// a `tokenizer.json`, so claiming support for it would silently produce a
+/// tokenizer built from garbage. Convert it to `tokenizer.json` first.
 enum ModelPathKind {
     Json(PathBuf),
+    Gpt2VocabMerges { vocab: PathBuf, merges: PathBuf },
 }
 
 fn resolve_model_path<P: AsRef<Path>>(path: P) -> Result<ModelPathKind> {
@@ -24,7 +93,16 @@ fn resolve_model_path<P: AsRef<Path>>(path: P) -> Result<ModelPathKind> {
             return Ok(ModelPathKind::Json(json));
         }
 
-        bail!("No tokenizer.json found in directory: {}. Convert .model to tokenizer.json first.", p.display());
+        let vocab = p.join("vocab.json");
+        let merges = p.join("merges.txt");
+        if vocab.is_file() && merges.is_file() {
+            return Ok(ModelPathKind::Gpt2VocabMerges { vocab, merges });
+        }
+
+        bail!(
+            "No tokenizer.json or vocab.json+merges.txt found in directory: {}. Convert .model to tokenizer.json first.",
+            p.display()
+        );
     }
 
     if p.is_file() {
@@ -37,69 +115,411 @@ fn resolve_model_path<P: AsRef<Path>>(path: P) -> Result<ModelPathKind> {
             return Ok(ModelPathKind::Json(p.to_path_buf()));
         }
 
-        bail!("Provided file is not tokenizer.json: {}", p.display());
+        bail!(
+            "Provided file is not tokenizer.json: {}. Convert .model to tokenizer.json first.",
+            p.display()
+        );
     }
 
     bail!("Path does not exist: {}", p.display());
 }
 
-/// Initialize the global tokenizer from a directory containing `tokenizer.json`,
-/// or a direct path to `tokenizer.json`.
-pub fn init<P: AsRef<Path>>(path: P) -> Result<()> {
-    let model_kind = resolve_model_path(path)?;
-    let tokenizer = match model_kind {
-        ModelPathKind::Json(json_path) => Tokenizer::from_file(&json_path)
-            .map_err(|e| anyhow!("Failed to load tokenizer from {}: {}", json_path.display(), e))?,
+/// Build a `Tokenizer` from a resolved model path, registering any special
+/// tokens declared in a sibling `special_tokens_map.json` /
+/// `tokenizer_config.json`.
+fn build_tokenizer(model_kind: ModelPathKind) -> Result<Tokenizer> {
+    let (mut tokenizer, dir) = match model_kind {
+        ModelPathKind::Json(json_path) => {
+            let tokenizer = Tokenizer::from_file(&json_path).map_err(|e| {
+                anyhow!("Failed to load tokenizer from {}: {}", json_path.display(), e)
+            })?;
+            (tokenizer, json_path.parent().map(Path::to_path_buf))
+        }
+        ModelPathKind::Gpt2VocabMerges { vocab, merges } => {
+            let bpe = BPE::from_file(&vocab.to_string_lossy(), &merges.to_string_lossy())
+                .build()
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to build BPE tokenizer from {} / {}: {}",
+                        vocab.display(),
+                        merges.display(),
+                        e
+                    )
+                })?;
+            let mut tokenizer = Tokenizer::new(bpe);
+            // GPT-2 vocabularies store bytes as visible characters (e.g. `Ġ`
+            // for a leading space) rather than literal whitespace, so raw
+            // text must be byte-level mapped before BPE lookup and mapped
+            // back on the way out.
+            tokenizer
+                .with_pre_tokenizer(ByteLevel::default())
+                .with_decoder(ByteLevel::default());
+            (tokenizer, vocab.parent().map(Path::to_path_buf))
+        }
     };
 
-    TOKENIZER
-        .set(tokenizer)
-        .map_err(|_| anyhow!("Tokenizer already initialized"))?;
+    if let Some(dir) = dir {
+        load_special_tokens(&mut tokenizer, &dir)?;
+    }
+    Ok(tokenizer)
+}
+
+/// Load special tokens declared in a sibling `special_tokens_map.json` or
+/// `tokenizer_config.json`, if either is present, and register them on the
+/// tokenizer.
+fn load_special_tokens(tokenizer: &mut Tokenizer, dir: &Path) -> Result<()> {
+    let mut special_tokens = Vec::new();
+    for file_name in ["special_tokens_map.json", "tokenizer_config.json"] {
+        let path = dir.join(file_name);
+        if !path.is_file() {
+            continue;
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let value: serde_json::Value = serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("Failed to parse {}: {}", path.display(), e))?;
+        collect_special_tokens(&value, &mut special_tokens);
+    }
+
+    if !special_tokens.is_empty() {
+        tokenizer.add_special_tokens(&special_tokens);
+    }
     Ok(())
 }
 
-fn get_tokenizer() -> Result<&'static Tokenizer> {
-    TOKENIZER
-        .get()
-        .ok_or_else(|| anyhow!("Tokenizer is not initialized. Call init(path) first."))
+/// Recursively pull special-token strings out of a `special_tokens_map.json`
+/// / `tokenizer_config.json` style value, whether they appear as plain
+/// strings (e.g. `"eos_token": "</s>"`), as `{"content": "...", ...}`
+/// objects, or as lists (e.g. `additional_special_tokens`). Only entries whose
+/// key ends in `_token` or `_tokens` are read; other config strings such as
+/// `tokenizer_class` or `padding_side` are skipped.
+fn collect_special_tokens(value: &serde_json::Value, out: &mut Vec<AddedToken>) {
+    match value {
+        serde_json::Value::String(s) => out.push(AddedToken::from(s.clone(), true)),
+        serde_json::Value::Array(items) => {
+            for v in items {
+                collect_special_tokens(v, out);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            if let Some(content) = map.get("content").and_then(|v| v.as_str()) {
+                out.push(AddedToken::from(content.to_string(), true));
+            } else {
+                for (key, v) in map {
+                    if key.ends_with("_token") || key.ends_with("_tokens") {
+                        collect_special_tokens(v, out);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Initialize a named tokenizer slot with default settings (no special
+/// tokens added, no truncation or padding). See [`init_named_with_settings`]
+/// to configure special tokens, truncation, and padding at load time.
+pub fn init_named<P: AsRef<Path>>(name: &str, path: P) -> Result<()> {
+    init_named_with_settings(name, path, TokenizerSettings::default())
+}
+
+/// Initialize a named tokenizer slot from a directory containing
+/// `tokenizer.json` or a GPT-2 style `vocab.json`+`merges.txt` pair, or from a
+/// direct path to a `.json` tokenizer file, applying
+/// `settings` so counts and encodings reflect what the model will actually
+/// see. Re-initializing an existing name replaces it.
+pub fn init_named_with_settings<P: AsRef<Path>>(
+    name: &str,
+    path: P,
+    settings: TokenizerSettings,
+) -> Result<()> {
+    let model_kind = resolve_model_path(path)?;
+    let mut tokenizer = build_tokenizer(model_kind)?;
+    apply_settings(&mut tokenizer, &settings)?;
+
+    registry()
+        .lock()
+        .map_err(|_| anyhow!("Tokenizer registry lock was poisoned"))?
+        .insert(name.to_string(), Arc::new(TokenizerEntry { tokenizer, settings }));
+    Ok(())
+}
+
+/// Initialize the default tokenizer slot. See [`init_named_with_settings`]
+/// for the accepted path shapes.
+pub fn init<P: AsRef<Path>>(path: P) -> Result<()> {
+    init_named(DEFAULT_TOKENIZER_NAME, path)
+}
+
+fn get_entry_named(name: &str) -> Result<Arc<TokenizerEntry>> {
+    registry()
+        .lock()
+        .map_err(|_| anyhow!("Tokenizer registry lock was poisoned"))?
+        .get(name)
+        .cloned()
+        .ok_or_else(|| anyhow!("Tokenizer '{}' is not initialized. Call init_named(\"{}\", path) first.", name, name))
 }
 
-/// Internal count implementation
-fn count_internal(text: &str) -> Result<usize> {
-    let tok = get_tokenizer()?;
-    let encoding = tok
-        .encode(text, false)
+fn count_internal_named(name: &str, text: &str) -> Result<usize> {
+    let entry = get_entry_named(name)?;
+    let encoding = entry
+        .tokenizer
+        .encode(text, entry.settings.add_special_tokens)
         .map_err(|e| anyhow!("Failed to encode text: {}", e))?;
     Ok(encoding.len())
 }
 
+/// Count tokens using a named tokenizer slot.
+pub fn count_with(name: &str, text: &str) -> Result<usize> {
+    count_internal_named(name, text)
+}
+
+/// Count tokens for one call with `settings` overriding the slot's persisted
+/// configuration, without mutating it.
+pub fn count_with_settings(name: &str, text: &str, settings: &TokenizerSettings) -> Result<usize> {
+    Ok(encode_with_settings(name, text, settings)?.len())
+}
+
 /// Encode to token ids (useful for deeper validation or debugging).
 pub fn encode(text: &str) -> Result<Vec<u32>> {
-    let tok = get_tokenizer()?;
-    let encoding = tok
-        .encode(text, false)
+    encode_with(DEFAULT_TOKENIZER_NAME, text)
+}
+
+/// Encode to token ids using a named tokenizer slot.
+pub fn encode_with(name: &str, text: &str) -> Result<Vec<u32>> {
+    let entry = get_entry_named(name)?;
+    let encoding = entry
+        .tokenizer
+        .encode(text, entry.settings.add_special_tokens)
         .map_err(|e| anyhow!("Failed to encode text: {}", e))?;
     Ok(encoding.get_ids().to_vec())
 }
 
+/// Encode to token ids for one call with `settings` overriding the slot's
+/// persisted configuration, without mutating it.
+pub fn encode_with_settings(name: &str, text: &str, settings: &TokenizerSettings) -> Result<Vec<u32>> {
+    let entry = get_entry_named(name)?;
+    let mut tokenizer = entry.tokenizer.clone();
+    apply_settings(&mut tokenizer, settings)?;
+    let encoding = tokenizer
+        .encode(text, settings.add_special_tokens)
+        .map_err(|e| anyhow!("Failed to encode text: {}", e))?;
+    Ok(encoding.get_ids().to_vec())
+}
+
+/// Encode to token ids together with each token's `(byte_start, byte_end)`
+/// span in `text` (matching `tokenizers`' own `Encoding::get_offsets`), for
+/// highlighting which slice of source text a token covers. These are *byte*
+/// offsets, not char or UTF-16 indices, so slice `text` directly with them
+/// rather than indexing by character.
+pub fn encode_with_offsets(text: &str) -> Result<Vec<(u32, usize, usize)>> {
+    encode_with_offsets_with(DEFAULT_TOKENIZER_NAME, text)
+}
+
+/// Encode with offsets using a named tokenizer slot.
+pub fn encode_with_offsets_with(name: &str, text: &str) -> Result<Vec<(u32, usize, usize)>> {
+    let entry = get_entry_named(name)?;
+    let encoding = entry
+        .tokenizer
+        .encode(text, entry.settings.add_special_tokens)
+        .map_err(|e| anyhow!("Failed to encode text: {}", e))?;
+    Ok(encoding
+        .get_ids()
+        .iter()
+        .zip(encoding.get_offsets())
+        .map(|(id, (start, end))| (*id, *start, *end))
+        .collect())
+}
+
+/// Like [`encode_with_offsets_with`], but encodes against a clone of the
+/// slot's tokenizer with truncation and padding cleared, so the full `text`
+/// is covered regardless of any length cap configured on the slot. Used by
+/// [`chunk_with`], whose own windowing is the thing responsible for bounding
+/// length.
+fn encode_offsets_unbounded(entry: &TokenizerEntry, text: &str) -> Result<Vec<(u32, usize, usize)>> {
+    let mut tokenizer = entry.tokenizer.clone();
+    tokenizer
+        .with_truncation(None)
+        .map_err(|e| anyhow!("Failed to clear truncation: {}", e))?;
+    tokenizer.with_padding(None);
+
+    let encoding = tokenizer
+        .encode(text, entry.settings.add_special_tokens)
+        .map_err(|e| anyhow!("Failed to encode text: {}", e))?;
+    Ok(encoding
+        .get_ids()
+        .iter()
+        .zip(encoding.get_offsets())
+        .map(|(id, (start, end))| (*id, *start, *end))
+        .collect())
+}
+
+/// Count tokens across many texts in one call. Delegates to `tokenizers`'
+/// `encode_batch`, which parallelizes the work with rayon internally.
+pub fn count_batch(texts: &[&str]) -> Result<Vec<usize>> {
+    count_batch_with(DEFAULT_TOKENIZER_NAME, texts)
+}
+
+/// Count tokens across many texts using a named tokenizer slot.
+pub fn count_batch_with(name: &str, texts: &[&str]) -> Result<Vec<usize>> {
+    let entry = get_entry_named(name)?;
+    let encodings = entry
+        .tokenizer
+        .encode_batch(texts.to_vec(), entry.settings.add_special_tokens)
+        .map_err(|e| anyhow!("Failed to encode batch: {}", e))?;
+    Ok(encodings.iter().map(|encoding| encoding.len()).collect())
+}
+
+/// Encode many texts to token ids in one call. Delegates to `tokenizers`'
+/// `encode_batch`, which parallelizes the work with rayon internally.
+pub fn encode_batch(texts: &[&str]) -> Result<Vec<Vec<u32>>> {
+    encode_batch_with(DEFAULT_TOKENIZER_NAME, texts)
+}
+
+/// Encode many texts to token ids using a named tokenizer slot.
+pub fn encode_batch_with(name: &str, texts: &[&str]) -> Result<Vec<Vec<u32>>> {
+    let entry = get_entry_named(name)?;
+    let encodings = entry
+        .tokenizer
+        .encode_batch(texts.to_vec(), entry.settings.add_special_tokens)
+        .map_err(|e| anyhow!("Failed to encode batch: {}", e))?;
+    Ok(encodings
+        .iter()
+        .map(|encoding| encoding.get_ids().to_vec())
+        .collect())
+}
+
+/// Decode token ids back into text.
+pub fn decode(ids: &[u32], skip_special_tokens: bool) -> Result<String> {
+    decode_with(DEFAULT_TOKENIZER_NAME, ids, skip_special_tokens)
+}
+
+/// Decode token ids back into text using a named tokenizer slot.
+pub fn decode_with(name: &str, ids: &[u32], skip_special_tokens: bool) -> Result<String> {
+    let entry = get_entry_named(name)?;
+    entry
+        .tokenizer
+        .decode(ids, skip_special_tokens)
+        .map_err(|e| anyhow!("Failed to decode ids: {}", e))
+}
+
+/// Split `text` into consecutive windows of at most `max_tokens` tokens each,
+/// with `overlap` tokens shared between adjacent windows. Splits always land
+/// on token boundaries since each window is sliced out of `text` using the
+/// byte offsets of its first and last token, never mid-token.
+///
+/// Any truncation/padding configured on the named slot via
+/// [`TokenizerSettings`] is ignored here: `chunk` is itself the mechanism for
+/// fitting a long document into bounded windows, so honoring a slot's own
+/// truncation would silently drop everything past that length instead of
+/// windowing it.
+pub fn chunk(text: &str, max_tokens: usize, overlap: usize) -> Result<Vec<String>> {
+    chunk_with(DEFAULT_TOKENIZER_NAME, text, max_tokens, overlap)
+}
+
+/// Chunk `text` into token windows using a named tokenizer slot. See
+/// [`chunk`] for how this interacts with the slot's configured truncation.
+pub fn chunk_with(name: &str, text: &str, max_tokens: usize, overlap: usize) -> Result<Vec<String>> {
+    if max_tokens == 0 {
+        bail!("max_tokens must be greater than 0");
+    }
+    if overlap >= max_tokens {
+        bail!("overlap ({}) must be smaller than max_tokens ({})", overlap, max_tokens);
+    }
+
+    let entry = get_entry_named(name)?;
+    let tokens = encode_offsets_unbounded(&entry, text)?;
+    if tokens.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let stride = max_tokens - overlap;
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < tokens.len() {
+        let end = (start + max_tokens).min(tokens.len());
+        let window = &tokens[start..end];
+        let (_, byte_start, _) = window.first().expect("window is never empty");
+        let (_, _, byte_end) = window.last().expect("window is never empty");
+        chunks.push(text[*byte_start..*byte_end].to_string());
+
+        if end == tokens.len() {
+            break;
+        }
+        start += stride;
+    }
+    Ok(chunks)
+}
+
 // ===== WASM exports =====
 #[wasm_bindgen]
 pub fn init_from_json(json: String) -> Result<(), JsValue> {
+    init_named_from_json(DEFAULT_TOKENIZER_NAME.to_string(), json)
+}
+
+#[wasm_bindgen]
+pub fn init_named_from_json(name: String, json: String) -> Result<(), JsValue> {
     let tokenizer = Tokenizer::from_str(&json).map_err(|e| JsValue::from_str(&e.to_string()))?;
-    TOKENIZER
-        .set(tokenizer)
-        .map_err(|_| JsValue::from_str("Tokenizer already initialized"))?;
+    let settings = TokenizerSettings::default();
+    registry()
+        .lock()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?
+        .insert(name, Arc::new(TokenizerEntry { tokenizer, settings }));
     Ok(())
 }
 
 #[wasm_bindgen]
 pub fn count(text: String) -> u32 {
-    match count_internal(&text) {
+    count_named(DEFAULT_TOKENIZER_NAME.to_string(), text)
+}
+
+#[wasm_bindgen]
+pub fn count_named(name: String, text: String) -> u32 {
+    match count_internal_named(&name, &text) {
         Ok(n) => u32::try_from(n).unwrap_or(u32::MAX),
         Err(_) => 0,
     }
 }
 
+#[wasm_bindgen]
+pub fn encode_named(name: String, text: String) -> Vec<u32> {
+    encode_with(&name, &text).unwrap_or_default()
+}
+
+#[wasm_bindgen(js_name = count_batch)]
+pub fn count_batch_js(texts: Vec<String>) -> Vec<u32> {
+    let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+    match count_batch_with(DEFAULT_TOKENIZER_NAME, &refs) {
+        Ok(counts) => counts
+            .into_iter()
+            .map(|n| u32::try_from(n).unwrap_or(u32::MAX))
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Encode to token ids with offsets, returned as a flat array of `[id,
+/// byte_start, byte_end]` triples so it can cross the WASM boundary as a
+/// single typed array. The offsets are byte indices into the UTF-8 encoding
+/// of `text`, not char or UTF-16 indices.
+#[wasm_bindgen]
+pub fn encode_offsets(text: String) -> Vec<u32> {
+    match encode_with_offsets(&text) {
+        Ok(tokens) => tokens
+            .into_iter()
+            .flat_map(|(id, start, end)| {
+                [id, u32::try_from(start).unwrap_or(u32::MAX), u32::try_from(end).unwrap_or(u32::MAX)]
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[wasm_bindgen(js_name = decode)]
+pub fn decode_ids(ids: Vec<u32>) -> String {
+    decode_with(DEFAULT_TOKENIZER_NAME, &ids, true).unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +529,42 @@ mod tests {
         let err = init("./not-exists").unwrap_err();
         assert!(err.to_string().contains("not exist"));
     }
+
+    #[test]
+    fn uninitialized_named_tokenizer_yields_error() {
+        let err = count_with("some-unregistered-model", "hello").unwrap_err();
+        assert!(err.to_string().contains("is not initialized"));
+    }
+
+    #[test]
+    fn uninitialized_named_tokenizer_yields_error_for_batch() {
+        let err = count_batch_with("some-unregistered-model", &["hello", "world"]).unwrap_err();
+        assert!(err.to_string().contains("is not initialized"));
+    }
+
+    #[test]
+    fn uninitialized_named_tokenizer_yields_error_for_offsets() {
+        let err = encode_with_offsets_with("some-unregistered-model", "hello").unwrap_err();
+        assert!(err.to_string().contains("is not initialized"));
+    }
+
+    #[test]
+    fn uninitialized_named_tokenizer_yields_error_for_decode() {
+        let err = decode_with("some-unregistered-model", &[1, 2, 3], true).unwrap_err();
+        assert!(err.to_string().contains("is not initialized"));
+    }
+
+    #[test]
+    fn chunk_rejects_overlap_not_smaller_than_max_tokens() {
+        let err = chunk("hello world", 4, 4).unwrap_err();
+        assert!(err.to_string().contains("overlap"));
+    }
+
+    #[test]
+    fn default_tokenizer_settings_match_legacy_behavior() {
+        let settings = TokenizerSettings::default();
+        assert!(!settings.add_special_tokens);
+        assert!(settings.truncation.is_none());
+        assert!(settings.padding.is_none());
+    }
 }